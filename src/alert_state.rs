@@ -0,0 +1,161 @@
+use crate::alerting::{Alert, AlertSeverity};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AlertStateError {
+    #[error("Failed to read alert state file: {0}")]
+    ReadError(String),
+    #[error("Failed to write alert state file: {0}")]
+    WriteError(String),
+    #[error("Failed to (de)serialize alert state: {0}")]
+    SerdeError(String),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AlertStateFile {
+    last_fired: HashMap<String, DateTime<Utc>>,
+}
+
+/// Tracks the last time each alert key fired so the caller can suppress repeat
+/// notifications until a configurable cooldown has elapsed, surviving restarts by
+/// persisting the map to `path` on every update.
+pub struct AlertStateManager {
+    path: PathBuf,
+    cooldown: Duration,
+    last_fired: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl AlertStateManager {
+    pub fn new(path: impl Into<PathBuf>, cooldown: Duration) -> Result<Self, AlertStateError> {
+        let path = path.into();
+
+        let last_fired = if path.exists() {
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|e| AlertStateError::ReadError(e.to_string()))?;
+            let file: AlertStateFile = serde_json::from_str(&contents)
+                .map_err(|e| AlertStateError::SerdeError(e.to_string()))?;
+            file.last_fired
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            path,
+            cooldown,
+            last_fired: Mutex::new(last_fired),
+        })
+    }
+
+    /// Returns true if `key` has never fired, or its cooldown has elapsed.
+    pub fn should_fire(&self, key: &str) -> bool {
+        let last_fired = self.last_fired.lock().unwrap();
+        match last_fired.get(key) {
+            Some(last) => Utc::now() - *last > self.cooldown,
+            None => true,
+        }
+    }
+
+    /// Records that `key` fired right now and persists the updated state to disk.
+    pub fn record_fired(&self, key: &str) -> Result<(), AlertStateError> {
+        {
+            let mut last_fired = self.last_fired.lock().unwrap();
+            last_fired.insert(key.to_string(), Utc::now());
+        }
+        self.persist()
+    }
+
+    /// Drops `key` so the next occurrence fires immediately. Returns an `Info`
+    /// "resolved" alert if `key` had previously fired, so callers can notify that a
+    /// condition cleared rather than staying silent until it recurs.
+    pub fn clear(&self, key: &str, source: &str) -> Result<Option<Alert>, AlertStateError> {
+        let existed = {
+            let mut last_fired = self.last_fired.lock().unwrap();
+            last_fired.remove(key).is_some()
+        };
+
+        if !existed {
+            return Ok(None);
+        }
+
+        self.persist()?;
+
+        Ok(Some(Alert {
+            timestamp: Utc::now(),
+            severity: AlertSeverity::Info,
+            source: source.to_string(),
+            message: format!("{} has recovered", source),
+            details: format!("Alert key '{}' cleared", key),
+        }))
+    }
+
+    fn persist(&self) -> Result<(), AlertStateError> {
+        let last_fired = self.last_fired.lock().unwrap();
+        let file = AlertStateFile {
+            last_fired: last_fired.clone(),
+        };
+        let contents = serde_json::to_string_pretty(&file)
+            .map_err(|e| AlertStateError::SerdeError(e.to_string()))?;
+        std::fs::write(&self.path, contents).map_err(|e| AlertStateError::WriteError(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Returns a fresh, not-yet-existing state file path in the system temp dir,
+    /// unique per call so concurrent tests don't collide.
+    fn temp_state_path() -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!("rsops_alert_state_test_{}_{}.json", std::process::id(), n))
+    }
+
+    #[test]
+    fn cooldown_suppresses_second_fire_within_window() {
+        let manager = AlertStateManager::new(temp_state_path(), Duration::minutes(5)).unwrap();
+
+        assert!(manager.should_fire("disk"));
+        manager.record_fired("disk").unwrap();
+        assert!(!manager.should_fire("disk"));
+    }
+
+    #[test]
+    fn fires_again_after_cooldown_elapses() {
+        let manager = AlertStateManager::new(temp_state_path(), Duration::milliseconds(10)).unwrap();
+
+        manager.record_fired("disk").unwrap();
+        assert!(!manager.should_fire("disk"));
+
+        std::thread::sleep(std::time::Duration::from_millis(30));
+        assert!(manager.should_fire("disk"));
+    }
+
+    #[test]
+    fn clear_returns_none_for_a_key_that_never_fired() {
+        let manager = AlertStateManager::new(temp_state_path(), Duration::minutes(5)).unwrap();
+
+        assert!(manager.clear("disk", "Disk").unwrap().is_none());
+    }
+
+    #[test]
+    fn clear_removes_the_key_and_returns_a_resolved_info_alert() {
+        let manager = AlertStateManager::new(temp_state_path(), Duration::minutes(5)).unwrap();
+
+        manager.record_fired("disk").unwrap();
+        let resolved = manager.clear("disk", "Disk").unwrap().expect("key had fired");
+        assert_eq!(resolved.severity, AlertSeverity::Info);
+        assert_eq!(resolved.source, "Disk");
+
+        // The key is gone, so it should fire immediately again rather than
+        // waiting out the cooldown.
+        assert!(manager.should_fire("disk"));
+    }
+}
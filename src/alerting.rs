@@ -1,8 +1,10 @@
-use crate::resource_monitor::ResourceMetrics;
+use crate::config::{ContainerResourceThresholdConfig, ExpressionRuleConfig};
 use crate::container_monitor::ContainerStatus;
+use crate::expression::{self, Expr};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use thiserror::Error;
 
 #[derive(Debug, Serialize, Clone)]
@@ -14,13 +16,24 @@ pub struct Alert {
     pub details: String,
 }
 
-#[derive(Debug, Serialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd, Eq, Ord)]
+#[serde(rename_all = "lowercase")]
 pub enum AlertSeverity {
     Info,
     Warning,
     Critical,
 }
 
+impl std::fmt::Display for AlertSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlertSeverity::Info => write!(f, "Info"),
+            AlertSeverity::Warning => write!(f, "Warning"),
+            AlertSeverity::Critical => write!(f, "Critical"),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum AlertError {
     #[error("Failed to evaluate alert condition: {0}")]
@@ -32,65 +45,49 @@ pub trait AlertRule {
     async fn evaluate(&self) -> Result<Option<Alert>, AlertError>;
 }
 
-pub struct ResourceAlertRule {
-    pub threshold: ResourceThreshold,
-    pub metrics: ResourceMetrics,
-}
-
 pub struct ContainerAlertRule {
     pub container: ContainerStatus,
 }
 
-#[derive(Debug)]
-pub struct ResourceThreshold {
-    pub cpu_threshold: f32,
-    pub memory_threshold: f32,
-    pub disk_threshold: f32,
+/// An alert rule compiled from a config-defined boolean expression (see
+/// `config::ExpressionRuleConfig`), evaluated against a snapshot of named metric
+/// variables rather than a hardcoded threshold comparison.
+pub struct ExpressionAlertRule {
+    pub config: ExpressionRuleConfig,
+    pub expr: Expr,
+    pub variables: HashMap<String, f64>,
 }
 
-impl ResourceAlertRule {
-    pub fn new(threshold: ResourceThreshold, metrics: ResourceMetrics) -> Self {
-        Self {
-            threshold,
-            metrics,
-        }
+impl ExpressionAlertRule {
+    pub fn new(
+        config: ExpressionRuleConfig,
+        variables: HashMap<String, f64>,
+    ) -> Result<Self, AlertError> {
+        let expr = expression::parse(&config.expression)
+            .map_err(|e| AlertError::EvaluationError(e.to_string()))?;
+        Ok(Self {
+            config,
+            expr,
+            variables,
+        })
     }
 }
 
 #[async_trait]
-impl AlertRule for ResourceAlertRule {
+impl AlertRule for ExpressionAlertRule {
     async fn evaluate(&self) -> Result<Option<Alert>, AlertError> {
-        let cpu_usage_percent = self.metrics.cpu_usage;
-        let memory_usage_percent = (self.metrics.memory_used as f32 / self.metrics.memory_total as f32) * 100.0;
-        let disk_usage_percent = (self.metrics.disk_used as f32 / self.metrics.disk_total as f32) * 100.0;
-
-        if cpu_usage_percent > self.threshold.cpu_threshold {
-            return Ok(Some(Alert {
-                timestamp: Utc::now(),
-                severity: AlertSeverity::Warning,
-                source: "CPU".to_string(),
-                message: format!("High CPU usage: {:.1}%", cpu_usage_percent),
-                details: format!("Threshold: {:.1}%", self.threshold.cpu_threshold),
-            }));
-        }
-
-        if memory_usage_percent > self.threshold.memory_threshold {
-            return Ok(Some(Alert {
-                timestamp: Utc::now(),
-                severity: AlertSeverity::Warning,
-                source: "Memory".to_string(),
-                message: format!("High memory usage: {:.1}%", memory_usage_percent),
-                details: format!("Threshold: {:.1}%", self.threshold.memory_threshold),
-            }));
-        }
+        let result = self
+            .expr
+            .eval(&self.variables)
+            .map_err(|e| AlertError::EvaluationError(e.to_string()))?;
 
-        if disk_usage_percent > self.threshold.disk_threshold {
+        if result != 0.0 {
             return Ok(Some(Alert {
                 timestamp: Utc::now(),
-                severity: AlertSeverity::Warning,
-                source: "Disk".to_string(),
-                message: format!("High disk usage: {:.1}%", disk_usage_percent),
-                details: format!("Threshold: {:.1}%", self.threshold.disk_threshold),
+                severity: self.config.severity.clone(),
+                source: self.config.source.clone(),
+                message: expression::render_template(&self.config.message, &self.variables),
+                details: format!("Expression: {}", self.config.expression),
             }));
         }
 
@@ -107,8 +104,8 @@ impl AlertRule for ContainerAlertRule {
                 severity: AlertSeverity::Critical,
                 source: "Container".to_string(),
                 message: format!("Container {} is not running", self.container.name),
-                details: format!("Container ID: {}, Status: {}", 
-                    self.container.container_id, 
+                details: format!("Container ID: {}, Status: {}",
+                    self.container.container_id,
                     self.container.status
                 ),
             }));
@@ -116,3 +113,60 @@ impl AlertRule for ContainerAlertRule {
         Ok(None)
     }
 }
+
+/// Fires a `Warning` alert when a running container's CPU percentage or
+/// memory-vs-limit ratio exceeds its configured threshold. Unlike
+/// `ContainerAlertRule`, this requires `container` to carry stats from
+/// `ContainerMonitor::get_container_stats`, not just `list_containers`.
+pub struct ContainerResourceAlertRule {
+    pub container: ContainerStatus,
+    pub threshold: ContainerResourceThresholdConfig,
+}
+
+#[async_trait]
+impl AlertRule for ContainerResourceAlertRule {
+    async fn evaluate(&self) -> Result<Option<Alert>, AlertError> {
+        if let Some(cpu_usage) = self.container.cpu_usage {
+            if cpu_usage > self.threshold.cpu_threshold {
+                return Ok(Some(Alert {
+                    timestamp: Utc::now(),
+                    severity: AlertSeverity::Warning,
+                    source: "Container".to_string(),
+                    message: format!(
+                        "Container {} high CPU usage: {:.1}%",
+                        self.container.name, cpu_usage
+                    ),
+                    details: format!(
+                        "Container ID: {}, Threshold: {:.1}%",
+                        self.container.container_id, self.threshold.cpu_threshold
+                    ),
+                }));
+            }
+        }
+
+        if let (Some(memory_usage), Some(memory_limit)) =
+            (self.container.memory_usage, self.container.memory_limit)
+        {
+            if memory_limit > 0 {
+                let memory_usage_percent = (memory_usage as f64 / memory_limit as f64) * 100.0;
+                if memory_usage_percent > self.threshold.memory_threshold {
+                    return Ok(Some(Alert {
+                        timestamp: Utc::now(),
+                        severity: AlertSeverity::Warning,
+                        source: "Container".to_string(),
+                        message: format!(
+                            "Container {} high memory usage: {:.1}%",
+                            self.container.name, memory_usage_percent
+                        ),
+                        details: format!(
+                            "Container ID: {}, Usage: {} bytes, Limit: {} bytes",
+                            self.container.container_id, memory_usage, memory_limit
+                        ),
+                    }));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,150 @@
+use crate::alerting::AlertSeverity;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to read config file: {0}")]
+    ReadError(String),
+    #[error("Failed to parse config file: {0}")]
+    ParseError(String),
+}
+
+/// A single expression-based alert rule, as loaded from `rules.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExpressionRuleConfig {
+    pub name: String,
+    pub expression: String,
+    pub severity: AlertSeverity,
+    pub source: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AlertRulesConfig {
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<ExpressionRuleConfig>,
+}
+
+impl AlertRulesConfig {
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| ConfigError::ReadError(e.to_string()))?;
+        toml::from_str(&contents).map_err(|e| ConfigError::ParseError(e.to_string()))
+    }
+}
+
+/// One entry in the notifier roster: which backend to construct and the routing
+/// rule (minimum severity, optional source allow-list) that gates it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NotifierConfig {
+    Email {
+        smtp_server: String,
+        smtp_port: u16,
+        username: String,
+        password: String,
+        from_address: String,
+        to_address: String,
+        min_severity: AlertSeverity,
+        #[serde(default)]
+        sources: Option<Vec<String>>,
+    },
+    Slack {
+        webhook_url: String,
+        channel: String,
+        min_severity: AlertSeverity,
+        #[serde(default)]
+        sources: Option<Vec<String>>,
+    },
+    Discord {
+        webhook_url: String,
+        min_severity: AlertSeverity,
+        #[serde(default)]
+        sources: Option<Vec<String>>,
+    },
+    Telegram {
+        bot_token: String,
+        chat_id: String,
+        min_severity: AlertSeverity,
+        #[serde(default)]
+        sources: Option<Vec<String>>,
+    },
+    Desktop {
+        min_severity: AlertSeverity,
+        #[serde(default)]
+        sources: Option<Vec<String>>,
+    },
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NotifiersConfig {
+    #[serde(default, rename = "notifier")]
+    pub notifiers: Vec<NotifierConfig>,
+}
+
+impl NotifiersConfig {
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| ConfigError::ReadError(e.to_string()))?;
+        toml::from_str(&contents).map_err(|e| ConfigError::ParseError(e.to_string()))
+    }
+}
+
+/// A rate-of-change alert rule: fires when `metric`, projected forward via
+/// linear regression over the trailing `window_minutes`, is on track to reach
+/// `capacity` within `projected_hours_threshold`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrendRuleConfig {
+    pub name: String,
+    pub metric: String,
+    pub source: String,
+    pub window_minutes: i64,
+    pub capacity: f64,
+    pub projected_hours_threshold: f64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TrendRulesConfig {
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<TrendRuleConfig>,
+}
+
+impl TrendRulesConfig {
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| ConfigError::ReadError(e.to_string()))?;
+        toml::from_str(&contents).map_err(|e| ConfigError::ParseError(e.to_string()))
+    }
+}
+
+/// CPU/memory thresholds for per-container resource alerting, as a percentage
+/// of one CPU core and of the container's memory limit respectively.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContainerResourceThresholdConfig {
+    pub cpu_threshold: f64,
+    pub memory_threshold: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContainerThresholdsConfig {
+    pub default: ContainerResourceThresholdConfig,
+    #[serde(default)]
+    pub overrides: HashMap<String, ContainerResourceThresholdConfig>,
+}
+
+impl ContainerThresholdsConfig {
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| ConfigError::ReadError(e.to_string()))?;
+        toml::from_str(&contents).map_err(|e| ConfigError::ParseError(e.to_string()))
+    }
+
+    /// Returns the per-container override for `container_name` if one is
+    /// configured, otherwise the default threshold.
+    pub fn threshold_for(&self, container_name: &str) -> &ContainerResourceThresholdConfig {
+        self.overrides.get(container_name).unwrap_or(&self.default)
+    }
+}
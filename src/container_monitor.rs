@@ -1,8 +1,15 @@
 use async_trait::async_trait;
 use bollard::Docker;
-use bollard::container::ListContainersOptions;
+use bollard::container::{ListContainersOptions, Stats, StatsOptions};
+use futures_util::stream::StreamExt;
 use thiserror::Error;
 use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// How far apart to take the two one-shot stats samples `get_container_stats`
+/// needs to compute a CPU percentage, the same way `docker stats` does.
+const STATS_SAMPLE_INTERVAL: Duration = Duration::from_millis(200);
 
 #[derive(Debug, Clone)]
 pub struct ContainerStatus {
@@ -11,6 +18,7 @@ pub struct ContainerStatus {
     pub status: String,
     pub running: bool,
     pub memory_usage: Option<u64>,
+    pub memory_limit: Option<u64>,
     pub cpu_usage: Option<f64>,
 }
 
@@ -36,9 +44,26 @@ impl DockerContainerMonitor {
     pub fn new() -> Result<Self, ContainerError> {
         let docker = Docker::connect_with_local_defaults()
             .map_err(|e| ContainerError::ConnectionError(e.to_string()))?;
-        
+
         Ok(Self { docker })
     }
+
+    /// Pulls a single stats reading for `container_id`. `Docker::stats` is a
+    /// stream; `one_shot: true, stream: false` asks the daemon for exactly one
+    /// sample instead of a continuous feed.
+    async fn sample_stats(&self, container_id: &str) -> Result<Stats, ContainerError> {
+        let options = Some(StatsOptions {
+            stream: false,
+            one_shot: true,
+        });
+
+        self.docker
+            .stats(container_id, options)
+            .next()
+            .await
+            .ok_or_else(|| ContainerError::MonitoringError("no stats returned".to_string()))?
+            .map_err(|e| ContainerError::MonitoringError(e.to_string()))
+    }
 }
 
 #[async_trait]
@@ -72,6 +97,7 @@ impl ContainerMonitor for DockerContainerMonitor {
                 status,
                 running,
                 memory_usage: None,
+                memory_limit: None,
                 cpu_usage: None,
             });
         }
@@ -80,10 +106,13 @@ impl ContainerMonitor for DockerContainerMonitor {
     }
 
     async fn get_container_stats(&self, container_id: &str) -> Result<ContainerStatus, ContainerError> {
-        let stats = self.docker
-            .stats_once(container_id)
-            .await
-            .map_err(|e| ContainerError::MonitoringError(e.to_string()))?;
+        // Docker's own CPU percentage is a delta between two samples, not a point
+        // reading, so take two one-shot stats samples a short interval apart.
+        let first = self.sample_stats(container_id).await?;
+
+        sleep(STATS_SAMPLE_INTERVAL).await;
+
+        let second = self.sample_stats(container_id).await?;
 
         let container = self.docker
             .inspect_container(container_id, None)
@@ -93,22 +122,31 @@ impl ContainerMonitor for DockerContainerMonitor {
         let name = container.name.unwrap_or_default()
             .trim_start_matches('/')
             .to_string();
-        
-        let status = container.state
-            .and_then(|s| s.status)
-            .unwrap_or_default();
-        
-        let running = container.state
-            .and_then(|s| s.running)
-            .unwrap_or(false);
+
+        let state = container.state.unwrap_or_default();
+        let status = state.status.map(|s| s.to_string()).unwrap_or_default();
+        let running = state.running.unwrap_or(false);
+
+        let cpu_delta = second.cpu_stats.cpu_usage.total_usage as f64
+            - first.cpu_stats.cpu_usage.total_usage as f64;
+        let system_delta = second.cpu_stats.system_cpu_usage.unwrap_or(0) as f64
+            - first.cpu_stats.system_cpu_usage.unwrap_or(0) as f64;
+        let online_cpus = second.cpu_stats.online_cpus.unwrap_or(1) as f64;
+
+        let cpu_usage_percent = if system_delta > 0.0 {
+            (cpu_delta / system_delta) * online_cpus * 100.0
+        } else {
+            0.0
+        };
 
         Ok(ContainerStatus {
             container_id: container_id.to_string(),
             name,
             status,
             running,
-            memory_usage: Some(stats.memory_stats.usage.unwrap_or(0)),
-            cpu_usage: Some(stats.cpu_stats.cpu_usage.total_usage as f64),
+            memory_usage: second.memory_stats.usage,
+            memory_limit: second.memory_stats.limit,
+            cpu_usage: Some(cpu_usage_percent),
         })
     }
 }
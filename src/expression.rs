@@ -0,0 +1,455 @@
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ExpressionError {
+    #[error("Unexpected character '{0}' in expression")]
+    UnexpectedChar(char),
+    #[error("Unexpected end of expression")]
+    UnexpectedEnd,
+    #[error("Unexpected token: {0}")]
+    UnexpectedToken(String),
+    #[error("Unknown variable: {0}")]
+    UnknownVariable(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+    Eq,
+    Ne,
+    And,
+    Or,
+    LParen,
+    RParen,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars: Peekable<Chars> = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            ' ' | '\t' | '\n' | '\r' => {
+                chars.next();
+            }
+            '+' => {
+                chars.next();
+                tokens.push(Token::Plus);
+            }
+            '-' => {
+                chars.next();
+                tokens.push(Token::Minus);
+            }
+            '*' => {
+                chars.next();
+                tokens.push(Token::Star);
+            }
+            '/' => {
+                chars.next();
+                tokens.push(Token::Slash);
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RParen);
+            }
+            '>' => {
+                chars.next();
+                if chars.peek() == Some(&'=') {
+                    chars.next();
+                    tokens.push(Token::Ge);
+                } else {
+                    tokens.push(Token::Gt);
+                }
+            }
+            '<' => {
+                chars.next();
+                if chars.peek() == Some(&'=') {
+                    chars.next();
+                    tokens.push(Token::Le);
+                } else {
+                    tokens.push(Token::Lt);
+                }
+            }
+            '=' => {
+                chars.next();
+                if chars.peek() == Some(&'=') {
+                    chars.next();
+                    tokens.push(Token::Eq);
+                } else {
+                    return Err(ExpressionError::UnexpectedChar('='));
+                }
+            }
+            '!' => {
+                chars.next();
+                if chars.peek() == Some(&'=') {
+                    chars.next();
+                    tokens.push(Token::Ne);
+                } else {
+                    return Err(ExpressionError::UnexpectedChar('!'));
+                }
+            }
+            '&' => {
+                chars.next();
+                if chars.peek() == Some(&'&') {
+                    chars.next();
+                    tokens.push(Token::And);
+                } else {
+                    return Err(ExpressionError::UnexpectedChar('&'));
+                }
+            }
+            '|' => {
+                chars.next();
+                if chars.peek() == Some(&'|') {
+                    chars.next();
+                    tokens.push(Token::Or);
+                } else {
+                    return Err(ExpressionError::UnexpectedChar('|'));
+                }
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut number = String::new();
+                while let Some(&nc) = chars.peek() {
+                    if nc.is_ascii_digit() || nc == '.' {
+                        number.push(nc);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let value = number
+                    .parse::<f64>()
+                    .map_err(|_| ExpressionError::UnexpectedToken(number))?;
+                tokens.push(Token::Number(value));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut ident = String::new();
+                while let Some(&nc) = chars.peek() {
+                    if nc.is_alphanumeric() || nc == '_' {
+                        ident.push(nc);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            other => return Err(ExpressionError::UnexpectedChar(other)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// A parsed expression tree. Comparisons and boolean operators evaluate to `1.0`
+/// (true) or `0.0` (false), C-style, so they can be combined with `&&`/`||` and
+/// checked for truthiness with `result != 0.0`.
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Number(f64),
+    Var(String),
+    BinaryOp(Box<Expr>, BinOp, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+    Eq,
+    Ne,
+    And,
+    Or,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    // Precedence, lowest to highest: || , && , comparisons , + - , * /
+    fn parse_expr(&mut self) -> Result<Expr, ExpressionError> {
+        self.parse_or()
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, ExpressionError> {
+        let mut lhs = self.parse_and()?;
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.next();
+            let rhs = self.parse_and()?;
+            lhs = Expr::BinaryOp(Box::new(lhs), BinOp::Or, Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, ExpressionError> {
+        let mut lhs = self.parse_comparison()?;
+        while matches!(self.peek(), Some(Token::And)) {
+            self.next();
+            let rhs = self.parse_comparison()?;
+            lhs = Expr::BinaryOp(Box::new(lhs), BinOp::And, Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, ExpressionError> {
+        let lhs = self.parse_additive()?;
+        let op = match self.peek() {
+            Some(Token::Gt) => BinOp::Gt,
+            Some(Token::Lt) => BinOp::Lt,
+            Some(Token::Ge) => BinOp::Ge,
+            Some(Token::Le) => BinOp::Le,
+            Some(Token::Eq) => BinOp::Eq,
+            Some(Token::Ne) => BinOp::Ne,
+            _ => return Ok(lhs),
+        };
+        self.next();
+        let rhs = self.parse_additive()?;
+        Ok(Expr::BinaryOp(Box::new(lhs), op, Box::new(rhs)))
+    }
+
+    fn parse_additive(&mut self) -> Result<Expr, ExpressionError> {
+        let mut lhs = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => BinOp::Add,
+                Some(Token::Minus) => BinOp::Sub,
+                _ => break,
+            };
+            self.next();
+            let rhs = self.parse_multiplicative()?;
+            lhs = Expr::BinaryOp(Box::new(lhs), op, Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expr, ExpressionError> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Star) => BinOp::Mul,
+                Some(Token::Slash) => BinOp::Div,
+                _ => break,
+            };
+            self.next();
+            let rhs = self.parse_unary()?;
+            lhs = Expr::BinaryOp(Box::new(lhs), op, Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, ExpressionError> {
+        if matches!(self.peek(), Some(Token::Minus)) {
+            self.next();
+            let operand = self.parse_unary()?;
+            return Ok(Expr::BinaryOp(
+                Box::new(Expr::Number(0.0)),
+                BinOp::Sub,
+                Box::new(operand),
+            ));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, ExpressionError> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Number(n)),
+            Some(Token::Ident(name)) => Ok(Expr::Var(name)),
+            Some(Token::LParen) => {
+                let expr = self.parse_expr()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(expr),
+                    _ => Err(ExpressionError::UnexpectedToken(")".to_string())),
+                }
+            }
+            Some(other) => Err(ExpressionError::UnexpectedToken(format!("{:?}", other))),
+            None => Err(ExpressionError::UnexpectedEnd),
+        }
+    }
+}
+
+/// Parses a boolean/arithmetic expression like `cpu_usage > 80` or
+/// `memory_used / memory_total * 100 > 90 && cpu_usage > 50` into an `Expr`.
+pub fn parse(input: &str) -> Result<Expr, ExpressionError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_expr()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(ExpressionError::UnexpectedToken(format!(
+            "{:?}",
+            parser.tokens[parser.pos]
+        )));
+    }
+    Ok(expr)
+}
+
+impl Expr {
+    pub fn eval(&self, vars: &HashMap<String, f64>) -> Result<f64, ExpressionError> {
+        match self {
+            Expr::Number(n) => Ok(*n),
+            Expr::Var(name) => vars
+                .get(name)
+                .copied()
+                .ok_or_else(|| ExpressionError::UnknownVariable(name.clone())),
+            Expr::BinaryOp(lhs, op, rhs) => {
+                let lhs = lhs.eval(vars)?;
+                let rhs = rhs.eval(vars)?;
+                let truthy = |b: bool| if b { 1.0 } else { 0.0 };
+                Ok(match op {
+                    BinOp::Add => lhs + rhs,
+                    BinOp::Sub => lhs - rhs,
+                    BinOp::Mul => lhs * rhs,
+                    BinOp::Div => lhs / rhs,
+                    BinOp::Gt => truthy(lhs > rhs),
+                    BinOp::Lt => truthy(lhs < rhs),
+                    BinOp::Ge => truthy(lhs >= rhs),
+                    BinOp::Le => truthy(lhs <= rhs),
+                    BinOp::Eq => truthy(lhs == rhs),
+                    BinOp::Ne => truthy(lhs != rhs),
+                    BinOp::And => truthy(lhs != 0.0 && rhs != 0.0),
+                    BinOp::Or => truthy(lhs != 0.0 || rhs != 0.0),
+                })
+            }
+        }
+    }
+}
+
+/// Renders `{variable}` placeholders in `template` using values from `vars`,
+/// formatted to one decimal place. Unknown placeholders are left untouched.
+pub fn render_template(template: &str, vars: &HashMap<String, f64>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            output.push(c);
+            continue;
+        }
+
+        let mut name = String::new();
+        let mut closed = false;
+        for nc in chars.by_ref() {
+            if nc == '}' {
+                closed = true;
+                break;
+            }
+            name.push(nc);
+        }
+
+        if !closed {
+            output.push('{');
+            output.push_str(&name);
+            continue;
+        }
+
+        match vars.get(&name) {
+            Some(value) => output.push_str(&format!("{:.1}", value)),
+            None => {
+                output.push('{');
+                output.push_str(&name);
+                output.push('}');
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, f64)]) -> HashMap<String, f64> {
+        pairs.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn multiplicative_binds_tighter_than_additive() {
+        let expr = parse("2 + 3 * 4").unwrap();
+        assert_eq!(expr.eval(&HashMap::new()).unwrap(), 14.0);
+    }
+
+    #[test]
+    fn parens_override_precedence() {
+        let expr = parse("(2 + 3) * 4").unwrap();
+        assert_eq!(expr.eval(&HashMap::new()).unwrap(), 20.0);
+    }
+
+    #[test]
+    fn comparison_binds_looser_than_additive() {
+        let expr = parse("cpu_usage + 10 > 80").unwrap();
+        assert_eq!(expr.eval(&vars(&[("cpu_usage", 75.0)])).unwrap(), 1.0);
+        assert_eq!(expr.eval(&vars(&[("cpu_usage", 65.0)])).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        // Should parse as `a || (b && c)`, so a true `a` makes the whole expression
+        // true even though `b && c` is false.
+        let expr = parse("1 || 0 && 0").unwrap();
+        assert_eq!(expr.eval(&HashMap::new()).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn unary_minus() {
+        let expr = parse("-5 + 3").unwrap();
+        assert_eq!(expr.eval(&HashMap::new()).unwrap(), -2.0);
+    }
+
+    #[test]
+    fn division() {
+        let expr = parse("10 / 4").unwrap();
+        assert_eq!(expr.eval(&HashMap::new()).unwrap(), 2.5);
+    }
+
+    #[test]
+    fn unknown_variable_errors() {
+        let expr = parse("missing > 0").unwrap();
+        assert!(expr.eval(&HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn trailing_garbage_is_a_parse_error() {
+        assert!(parse("1 + 2 3").is_err());
+    }
+
+    #[test]
+    fn render_template_formats_known_and_skips_unknown_placeholders() {
+        let rendered = render_template(
+            "cpu at {cpu_usage}%, missing: {missing}",
+            &vars(&[("cpu_usage", 87.654)]),
+        );
+        assert_eq!(rendered, "cpu at 87.7%, missing: {missing}");
+    }
+}
@@ -1,15 +1,39 @@
+mod alert_state;
 mod alerting;
+mod config;
 mod container_monitor;
+mod expression;
+mod metric_store;
+mod metrics_exporter;
 mod notification;
 mod resource_monitor;
+mod routing;
+mod trend;
 
+use alert_state::AlertStateManager;
 use alerting::AlertRule;
-use alerting::{ContainerAlertRule, ResourceAlertRule, ResourceThreshold};
+use alerting::{ContainerAlertRule, ContainerResourceAlertRule, ExpressionAlertRule};
+use chrono::Duration as ChronoDuration;
+use config::{
+    AlertRulesConfig, ContainerResourceThresholdConfig, ContainerThresholdsConfig, NotifiersConfig,
+    TrendRulesConfig,
+};
 use container_monitor::{ContainerMonitor, DockerContainerMonitor};
-use notification::{EmailNotifier, NotificationSender, SlackNotifier};
+use metric_store::MetricStore;
+use metrics_exporter::{MetricsExporter, OtlpPusher};
 use resource_monitor::{ResourceMonitor, SystemResourceMonitor};
+use routing::NotificationRouter;
+use std::env;
 use std::sync::Arc;
 use tokio::time::{self, Duration};
+use trend::TrendAlertRule;
+
+const RULES_CONFIG_PATH: &str = "rules.toml";
+const NOTIFIERS_CONFIG_PATH: &str = "notifiers.toml";
+const TREND_RULES_CONFIG_PATH: &str = "trend_rules.toml";
+const CONTAINER_THRESHOLDS_CONFIG_PATH: &str = "container_thresholds.toml";
+const METRIC_STORE_HOST: &str = "localhost";
+const DEFAULT_METRICS_ADDR: &str = "0.0.0.0:9898";
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -20,31 +44,89 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let resource_monitor = SystemResourceMonitor::new();
     let container_monitor = DockerContainerMonitor::new()?;
 
-    // Initialize notification senders
-    let email_notifier = EmailNotifier::new(
-        "smtp.example.com".to_string(),
-        587,
-        "username".to_string(),
-        "password".to_string(),
-        "from@example.com".to_string(),
-        "to@example.com".to_string(),
-    );
-
-    let slack_notifier = SlackNotifier::new(
-        "https://hooks.slack.com/services/your/webhook/url".to_string(),
-        "#monitoring".to_string(),
-    );
-
-    let notifiers: Vec<Arc<dyn NotificationSender + Send + Sync>> =
-        vec![Arc::new(email_notifier), Arc::new(slack_notifier)];
-
-    // Set monitoring thresholds
-    let resource_threshold = ResourceThreshold {
-        cpu_threshold: 80.0,    // 80% CPU usage
-        memory_threshold: 90.0, // 90% memory usage
-        disk_threshold: 85.0,   // 85% disk usage
+    // Load the notifier roster and the routing rules (min severity, source
+    // allow-list) gating each one, instead of wiring notifiers inline here.
+    let notifiers_config = NotifiersConfig::load_from_file(NOTIFIERS_CONFIG_PATH).unwrap_or_else(|e| {
+        log::warn!(
+            "Failed to load {}: {}. Running with no notifiers configured.",
+            NOTIFIERS_CONFIG_PATH,
+            e
+        );
+        NotifiersConfig::default()
+    });
+    let router = NotificationRouter::from_config(&notifiers_config);
+
+    // Load operator-tunable alert rules instead of compiling thresholds in.
+    let rules_config = AlertRulesConfig::load_from_file(RULES_CONFIG_PATH).unwrap_or_else(|e| {
+        log::warn!(
+            "Failed to load {}: {}. Running with no resource alert rules.",
+            RULES_CONFIG_PATH,
+            e
+        );
+        AlertRulesConfig::default()
+    });
+
+    // Suppress repeat notifications for the same problem; state survives restarts.
+    let alert_state = AlertStateManager::new("alert_state.json", ChronoDuration::minutes(15))?;
+
+    // Per-container CPU/memory thresholds, with optional overrides by name.
+    let container_thresholds =
+        ContainerThresholdsConfig::load_from_file(CONTAINER_THRESHOLDS_CONFIG_PATH).unwrap_or_else(|e| {
+            log::warn!(
+                "Failed to load {}: {}. Using default container resource thresholds.",
+                CONTAINER_THRESHOLDS_CONFIG_PATH,
+                e
+            );
+            ContainerThresholdsConfig {
+                default: ContainerResourceThresholdConfig {
+                    cpu_threshold: 80.0,
+                    memory_threshold: 90.0,
+                },
+                overrides: std::collections::HashMap::new(),
+            }
+        });
+
+    // Load rate-of-change rules and connect to the time-series metric store. Both
+    // are optional: without a configured database, trend alerting is skipped and
+    // the static/expression alerting above keeps working unchanged.
+    let trend_rules_config = TrendRulesConfig::load_from_file(TREND_RULES_CONFIG_PATH).unwrap_or_else(|e| {
+        log::warn!(
+            "Failed to load {}: {}. Running with no trend alert rules.",
+            TREND_RULES_CONFIG_PATH,
+            e
+        );
+        TrendRulesConfig::default()
+    });
+    let metric_store = match env::var("DATABASE_URL") {
+        Ok(database_url) => match MetricStore::connect(&database_url).await {
+            Ok(store) => Some(store),
+            Err(e) => {
+                log::warn!("Failed to connect to metric store: {}. Trend alerting disabled.", e);
+                None
+            }
+        },
+        Err(_) => {
+            log::warn!("DATABASE_URL not set; trend alerting disabled.");
+            None
+        }
     };
 
+    // Expose collected metrics on /metrics for scrape-based observability stacks;
+    // the alerting path above is unaffected by whether anything scrapes it.
+    let metrics_exporter = Arc::new(MetricsExporter::new()?);
+    let metrics_addr: std::net::SocketAddr = env::var("METRICS_ADDR")
+        .unwrap_or_else(|_| DEFAULT_METRICS_ADDR.to_string())
+        .parse()?;
+    {
+        let metrics_exporter = metrics_exporter.clone();
+        tokio::spawn(async move {
+            if let Err(e) = metrics_exporter.serve(metrics_addr).await {
+                log::error!("Metrics exporter stopped: {}", e);
+            }
+        });
+    }
+    let otlp_pusher = env::var("OTLP_ENDPOINT").ok().map(OtlpPusher::new);
+
     println!("Starting monitoring system...");
 
     let mut interval = time::interval(Duration::from_secs(60));
@@ -52,15 +134,85 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     loop {
         interval.tick().await;
 
-        // Collect metrics
+        // Collect metrics and evaluate each configured expression rule against them.
         if let Ok(metrics) = resource_monitor.collect_metrics().await {
-            // Evaluate resource alerts
-            let resource_rule = ResourceAlertRule::new(resource_threshold.clone(), metrics);
-            if let Ok(Some(alert)) = resource_rule.evaluate().await {
-                // Send notifications
-                for notifier in &notifiers {
-                    if let Err(e) = notifier.send(&alert).await {
-                        log::error!("Failed to send notification: {}", e);
+            let variables = metrics.as_variables();
+
+            metrics_exporter.update_resource_metrics(&metrics);
+            if let Some(pusher) = otlp_pusher.clone() {
+                // Spawned so a slow or unreachable OTLP_ENDPOINT can't stall the
+                // alert evaluation below; this push is additive, not load-bearing.
+                tokio::spawn(async move {
+                    if let Err(e) = pusher.push(&metrics).await {
+                        log::error!("Failed to push metrics via OTLP: {}", e);
+                    }
+                });
+            }
+
+            for rule_config in &rules_config.rules {
+                let key = format!("resource:{}", rule_config.name);
+                let rule = match ExpressionAlertRule::new(rule_config.clone(), variables.clone()) {
+                    Ok(rule) => rule,
+                    Err(e) => {
+                        log::error!("Failed to compile rule '{}': {}", rule_config.name, e);
+                        continue;
+                    }
+                };
+
+                match rule.evaluate().await {
+                    Ok(Some(alert)) => {
+                        if alert_state.should_fire(&key) {
+                            router.dispatch(&alert).await;
+                            if let Err(e) = alert_state.record_fired(&key) {
+                                log::error!("Failed to persist alert state: {}", e);
+                            }
+                        }
+                    }
+                    Ok(None) => match alert_state.clear(&key, &rule_config.source) {
+                        Ok(Some(resolved)) => router.dispatch(&resolved).await,
+                        Ok(None) => {}
+                        Err(e) => log::error!("Failed to clear alert state: {}", e),
+                    },
+                    Err(e) => log::error!("Failed to evaluate rule '{}': {}", rule_config.name, e),
+                }
+            }
+
+            // Persist this tick's samples and evaluate trend rules against history.
+            if let Some(store) = &metric_store {
+                if let Err(e) = store.record(METRIC_STORE_HOST, &variables).await {
+                    log::error!("Failed to record metrics: {}", e);
+                }
+
+                for trend_rule_config in &trend_rules_config.rules {
+                    let key = format!("trend:{}", trend_rule_config.name);
+                    let window = ChronoDuration::minutes(trend_rule_config.window_minutes);
+                    let samples = match store
+                        .query_window(METRIC_STORE_HOST, &trend_rule_config.metric, window)
+                        .await
+                    {
+                        Ok(samples) => samples,
+                        Err(e) => {
+                            log::error!("Failed to query metric store for '{}': {}", trend_rule_config.name, e);
+                            continue;
+                        }
+                    };
+
+                    let rule = TrendAlertRule::new(trend_rule_config.clone(), samples);
+                    match rule.evaluate().await {
+                        Ok(Some(alert)) => {
+                            if alert_state.should_fire(&key) {
+                                router.dispatch(&alert).await;
+                                if let Err(e) = alert_state.record_fired(&key) {
+                                    log::error!("Failed to persist alert state: {}", e);
+                                }
+                            }
+                        }
+                        Ok(None) => match alert_state.clear(&key, &trend_rule_config.source) {
+                            Ok(Some(resolved)) => router.dispatch(&resolved).await,
+                            Ok(None) => {}
+                            Err(e) => log::error!("Failed to clear alert state: {}", e),
+                        },
+                        Err(e) => log::error!("Failed to evaluate trend rule '{}': {}", trend_rule_config.name, e),
                     }
                 }
             }
@@ -69,13 +221,58 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Monitor containers
         if let Ok(containers) = container_monitor.list_containers().await {
             for container in containers {
+                let key = format!("container:{}", container.container_id);
+                let container_id = container.container_id.clone();
+                let running = container.running;
+                metrics_exporter.update_container_status(&container);
                 let container_rule = ContainerAlertRule { container };
-                if let Ok(Some(alert)) = container_rule.evaluate().await {
-                    // Send notifications
-                    for notifier in &notifiers {
-                        if let Err(e) = notifier.send(&alert).await {
-                            log::error!("Failed to send notification: {}", e);
+                match container_rule.evaluate().await {
+                    Ok(Some(alert)) => {
+                        if alert_state.should_fire(&key) {
+                            router.dispatch(&alert).await;
+                            if let Err(e) = alert_state.record_fired(&key) {
+                                log::error!("Failed to persist alert state: {}", e);
+                            }
+                        }
+                    }
+                    Ok(None) => match alert_state.clear(&key, "Container") {
+                        Ok(Some(resolved)) => router.dispatch(&resolved).await,
+                        Ok(None) => {}
+                        Err(e) => log::error!("Failed to clear alert state: {}", e),
+                    },
+                    Err(e) => log::error!("Failed to evaluate container alert: {}", e),
+                }
+
+                // Pull per-container CPU/memory stats for running containers, so
+                // resource exhaustion is visible before the container dies.
+                if running {
+                    match container_monitor.get_container_stats(&container_id).await {
+                        Ok(stats) => {
+                            metrics_exporter.update_container_status(&stats);
+                            let threshold = container_thresholds.threshold_for(&stats.name).clone();
+                            let resource_key = format!("container_resource:{}", container_id);
+                            let resource_rule = ContainerResourceAlertRule {
+                                container: stats,
+                                threshold,
+                            };
+                            match resource_rule.evaluate().await {
+                                Ok(Some(alert)) => {
+                                    if alert_state.should_fire(&resource_key) {
+                                        router.dispatch(&alert).await;
+                                        if let Err(e) = alert_state.record_fired(&resource_key) {
+                                            log::error!("Failed to persist alert state: {}", e);
+                                        }
+                                    }
+                                }
+                                Ok(None) => match alert_state.clear(&resource_key, "Container") {
+                                    Ok(Some(resolved)) => router.dispatch(&resolved).await,
+                                    Ok(None) => {}
+                                    Err(e) => log::error!("Failed to clear alert state: {}", e),
+                                },
+                                Err(e) => log::error!("Failed to evaluate container resource alert: {}", e),
+                            }
                         }
+                        Err(e) => log::error!("Failed to get stats for container {}: {}", container_id, e),
                     }
                 }
             }
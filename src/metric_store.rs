@@ -0,0 +1,117 @@
+use bb8::Pool;
+use bb8_postgres::PostgresConnectionManager;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use thiserror::Error;
+use tokio_postgres::NoTls;
+
+#[derive(Error, Debug)]
+pub enum MetricStoreError {
+    #[error("Failed to connect to metric store: {0}")]
+    ConnectionError(String),
+    #[error("Failed to query metric store: {0}")]
+    QueryError(String),
+}
+
+/// Persists `ResourceMetrics` samples to Postgres, timestamped and keyed by
+/// host, so trend-based alert rules can query history instead of only ever
+/// seeing the latest tick.
+pub struct MetricStore {
+    pool: Pool<PostgresConnectionManager<NoTls>>,
+}
+
+impl MetricStore {
+    pub async fn connect(database_url: &str) -> Result<Self, MetricStoreError> {
+        let config = database_url
+            .parse()
+            .map_err(|e: tokio_postgres::Error| MetricStoreError::ConnectionError(e.to_string()))?;
+        let manager = PostgresConnectionManager::new(config, NoTls);
+        let pool = Pool::builder()
+            .build(manager)
+            .await
+            .map_err(|e| MetricStoreError::ConnectionError(e.to_string()))?;
+
+        {
+            let conn = pool
+                .get()
+                .await
+                .map_err(|e| MetricStoreError::ConnectionError(e.to_string()))?;
+            // `recorded_at` is stored as milliseconds since the Unix epoch rather than
+            // TIMESTAMPTZ: tokio-postgres only round-trips `chrono::DateTime<Utc>`
+            // with its `with-chrono-0_4` feature enabled, and `i64` needs nothing extra.
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS metric_samples (
+                    host TEXT NOT NULL,
+                    metric TEXT NOT NULL,
+                    value DOUBLE PRECISION NOT NULL,
+                    recorded_at BIGINT NOT NULL
+                )",
+                &[],
+            )
+            .await
+            .map_err(|e| MetricStoreError::QueryError(e.to_string()))?;
+        }
+
+        Ok(Self { pool })
+    }
+
+    /// Writes one row per metric variable, timestamped now, for `host`.
+    pub async fn record(
+        &self,
+        host: &str,
+        metrics: &HashMap<String, f64>,
+    ) -> Result<(), MetricStoreError> {
+        let conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| MetricStoreError::ConnectionError(e.to_string()))?;
+        let now = Utc::now().timestamp_millis();
+
+        for (metric, value) in metrics {
+            conn.execute(
+                "INSERT INTO metric_samples (host, metric, value, recorded_at) VALUES ($1, $2, $3, $4)",
+                &[&host, metric, value, &now],
+            )
+            .await
+            .map_err(|e| MetricStoreError::QueryError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns `(timestamp, value)` samples for `metric` on `host` within the
+    /// trailing `window`, oldest first.
+    pub async fn query_window(
+        &self,
+        host: &str,
+        metric: &str,
+        window: Duration,
+    ) -> Result<Vec<(DateTime<Utc>, f64)>, MetricStoreError> {
+        let conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| MetricStoreError::ConnectionError(e.to_string()))?;
+        let since = (Utc::now() - window).timestamp_millis();
+
+        let rows = conn
+            .query(
+                "SELECT recorded_at, value FROM metric_samples
+                 WHERE host = $1 AND metric = $2 AND recorded_at >= $3
+                 ORDER BY recorded_at ASC",
+                &[&host, &metric, &since],
+            )
+            .await
+            .map_err(|e| MetricStoreError::QueryError(e.to_string()))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let recorded_at_ms: i64 = row.get(0);
+                let timestamp = DateTime::from_timestamp_millis(recorded_at_ms).unwrap_or_else(Utc::now);
+                (timestamp, row.get(1))
+            })
+            .collect())
+    }
+}
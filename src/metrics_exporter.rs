@@ -0,0 +1,191 @@
+use crate::container_monitor::ContainerStatus;
+use crate::resource_monitor::ResourceMetrics;
+use chrono::Utc;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server};
+use prometheus::{Encoder, Gauge, GaugeVec, Opts, Registry, TextEncoder};
+use reqwest::Client;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MetricsExporterError {
+    #[error("Failed to register metric: {0}")]
+    RegistrationError(String),
+    #[error("Metrics server error: {0}")]
+    ServerError(String),
+}
+
+/// Exposes collected `ResourceMetrics`/`ContainerStatus` values over `/metrics`
+/// in Prometheus text format, so existing scrape-based observability stacks can
+/// graph and alert on them without going through the built-in notifiers.
+pub struct MetricsExporter {
+    registry: Registry,
+    cpu_usage: Gauge,
+    memory_used: Gauge,
+    disk_used: Gauge,
+    container_running: GaugeVec,
+    container_memory: GaugeVec,
+}
+
+impl MetricsExporter {
+    pub fn new() -> Result<Self, MetricsExporterError> {
+        let registry = Registry::new();
+
+        let cpu_usage = Gauge::new("rsops_cpu_usage_percent", "Current system-wide CPU usage percentage")
+            .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+        let memory_used = Gauge::new("rsops_memory_used_bytes", "Current memory used, in bytes")
+            .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+        let disk_used = Gauge::new("rsops_disk_used_bytes", "Current disk used, in bytes")
+            .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+        let container_running = GaugeVec::new(
+            Opts::new("rsops_container_running", "Whether a container is running (1) or stopped (0)"),
+            &["name", "id"],
+        )
+        .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+        let container_memory = GaugeVec::new(
+            Opts::new("rsops_container_memory_bytes", "Per-container memory usage, in bytes"),
+            &["name", "id"],
+        )
+        .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+
+        registry
+            .register(Box::new(cpu_usage.clone()))
+            .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+        registry
+            .register(Box::new(memory_used.clone()))
+            .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+        registry
+            .register(Box::new(disk_used.clone()))
+            .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+        registry
+            .register(Box::new(container_running.clone()))
+            .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+        registry
+            .register(Box::new(container_memory.clone()))
+            .map_err(|e| MetricsExporterError::RegistrationError(e.to_string()))?;
+
+        Ok(Self {
+            registry,
+            cpu_usage,
+            memory_used,
+            disk_used,
+            container_running,
+            container_memory,
+        })
+    }
+
+    pub fn update_resource_metrics(&self, metrics: &ResourceMetrics) {
+        self.cpu_usage.set(metrics.cpu_usage as f64);
+        self.memory_used.set(metrics.memory_used as f64);
+        self.disk_used.set(metrics.disk_used as f64);
+    }
+
+    pub fn update_container_status(&self, container: &ContainerStatus) {
+        self.container_running
+            .with_label_values(&[&container.name, &container.container_id])
+            .set(if container.running { 1.0 } else { 0.0 });
+
+        if let Some(memory_usage) = container.memory_usage {
+            self.container_memory
+                .with_label_values(&[&container.name, &container.container_id])
+                .set(memory_usage as f64);
+        }
+    }
+
+    fn gather(&self) -> Vec<u8> {
+        let encoder = TextEncoder::new();
+        let metric_families = self.registry.gather();
+        let mut buffer = Vec::new();
+        // The text encoder only fails on a broken `Write`, which a `Vec` never is.
+        encoder.encode(&metric_families, &mut buffer).expect("encoding to a Vec cannot fail");
+        buffer
+    }
+
+    /// Serves `/metrics` on `addr` until the process exits. Intended to be run
+    /// in its own task via `tokio::spawn`.
+    pub async fn serve(self: Arc<Self>, addr: SocketAddr) -> Result<(), MetricsExporterError> {
+        let make_svc = make_service_fn(move |_conn| {
+            let exporter = self.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |_req: Request<Body>| {
+                    let exporter = exporter.clone();
+                    async move { Ok::<_, Infallible>(Response::new(Body::from(exporter.gather()))) }
+                }))
+            }
+        });
+
+        Server::bind(&addr)
+            .serve(make_svc)
+            .await
+            .map_err(|e| MetricsExporterError::ServerError(e.to_string()))
+    }
+}
+
+/// Best-effort push of the latest resource metrics to an OpenTelemetry
+/// collector's OTLP/HTTP JSON endpoint, for setups that push rather than scrape.
+#[derive(Clone)]
+pub struct OtlpPusher {
+    client: Client,
+    endpoint: String,
+}
+
+impl OtlpPusher {
+    pub fn new(endpoint: String) -> Self {
+        Self {
+            client: Client::new(),
+            endpoint,
+        }
+    }
+
+    pub async fn push(&self, metrics: &ResourceMetrics) -> Result<(), MetricsExporterError> {
+        let time_unix_nano = Utc::now()
+            .timestamp_nanos_opt()
+            .unwrap_or(0)
+            .to_string();
+
+        let gauge_metric = |name: &str, value: f64| {
+            serde_json::json!({
+                "name": name,
+                "gauge": {
+                    "dataPoints": [{
+                        "asDouble": value,
+                        "timeUnixNano": time_unix_nano,
+                    }],
+                },
+            })
+        };
+
+        // OTLP/HTTP JSON envelope: resourceMetrics -> scopeMetrics -> metrics,
+        // per https://github.com/open-telemetry/opentelemetry-proto's JSON mapping.
+        let payload = serde_json::json!({
+            "resourceMetrics": [{
+                "resource": {
+                    "attributes": [{
+                        "key": "service.name",
+                        "value": { "stringValue": "rsops" },
+                    }],
+                },
+                "scopeMetrics": [{
+                    "scope": { "name": "rsops" },
+                    "metrics": [
+                        gauge_metric("rsops_cpu_usage_percent", metrics.cpu_usage as f64),
+                        gauge_metric("rsops_memory_used_bytes", metrics.memory_used as f64),
+                        gauge_metric("rsops_disk_used_bytes", metrics.disk_used as f64),
+                    ],
+                }],
+            }],
+        });
+
+        self.client
+            .post(&self.endpoint)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| MetricsExporterError::ServerError(e.to_string()))?;
+
+        Ok(())
+    }
+}
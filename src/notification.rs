@@ -12,6 +12,12 @@ pub enum NotificationError {
     EmailError(String),
     #[error("Failed to send Slack notification: {0}")]
     SlackError(String),
+    #[error("Failed to send Discord notification: {0}")]
+    DiscordError(String),
+    #[error("Failed to send Telegram notification: {0}")]
+    TelegramError(String),
+    #[error("Failed to show desktop notification: {0}")]
+    DesktopError(String),
     #[error("Failed to send notification: {0}")]
     GeneralError(String),
 }
@@ -117,3 +123,108 @@ impl NotificationSender for SlackNotifier {
         Ok(())
     }
 }
+
+pub struct DiscordNotifier {
+    webhook_url: String,
+}
+
+impl DiscordNotifier {
+    pub fn new(webhook_url: String) -> Self {
+        Self { webhook_url }
+    }
+}
+
+#[async_trait]
+impl NotificationSender for DiscordNotifier {
+    async fn send(&self, alert: &Alert) -> Result<(), NotificationError> {
+        let client = Client::new();
+
+        let content = format!(
+            "**[{}] {} Alert**\nMessage: {}\nDetails: {}\nTimestamp: {}",
+            alert.severity, alert.source, alert.message, alert.details, alert.timestamp
+        );
+
+        let payload = json!({ "content": content });
+
+        client
+            .post(&self.webhook_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| NotificationError::DiscordError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+pub struct TelegramNotifier {
+    bot_token: String,
+    chat_id: String,
+}
+
+impl TelegramNotifier {
+    pub fn new(bot_token: String, chat_id: String) -> Self {
+        Self { bot_token, chat_id }
+    }
+}
+
+#[async_trait]
+impl NotificationSender for TelegramNotifier {
+    async fn send(&self, alert: &Alert) -> Result<(), NotificationError> {
+        let client = Client::new();
+
+        let text = format!(
+            "[{}] {} Alert\nMessage: {}\nDetails: {}\nTimestamp: {}",
+            alert.severity, alert.source, alert.message, alert.details, alert.timestamp
+        );
+
+        let url = format!("https://api.telegram.org/bot{}/sendMessage", self.bot_token);
+        let payload = json!({
+            "chat_id": self.chat_id,
+            "text": text,
+        });
+
+        client
+            .post(&url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| NotificationError::TelegramError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+pub struct DesktopNotifier;
+
+impl DesktopNotifier {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DesktopNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NotificationSender for DesktopNotifier {
+    async fn send(&self, alert: &Alert) -> Result<(), NotificationError> {
+        let summary = format!("[{}] {}", alert.severity, alert.source);
+        let body = alert.message.clone();
+
+        tokio::task::spawn_blocking(move || {
+            notify_rust::Notification::new()
+                .summary(&summary)
+                .body(&body)
+                .show()
+        })
+        .await
+        .map_err(|e| NotificationError::DesktopError(e.to_string()))?
+        .map_err(|e| NotificationError::DesktopError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use async_trait::async_trait;
+use std::collections::HashMap;
 use sysinfo::{System, SystemExt, CpuExt};
 use thiserror::Error;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct ResourceMetrics {
     pub cpu_usage: f32,
     pub memory_used: u64,
@@ -11,6 +12,20 @@ pub struct ResourceMetrics {
     pub disk_total: u64,
 }
 
+impl ResourceMetrics {
+    /// Exposes these metrics as named variables for the expression-based alert
+    /// rules to evaluate against.
+    pub fn as_variables(&self) -> HashMap<String, f64> {
+        let mut vars = HashMap::new();
+        vars.insert("cpu_usage".to_string(), self.cpu_usage as f64);
+        vars.insert("memory_used".to_string(), self.memory_used as f64);
+        vars.insert("memory_total".to_string(), self.memory_total as f64);
+        vars.insert("disk_used".to_string(), self.disk_used as f64);
+        vars.insert("disk_total".to_string(), self.disk_total as f64);
+        vars
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ResourceError {
     #[error("Failed to collect system metrics: {0}")]
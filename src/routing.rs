@@ -0,0 +1,172 @@
+use crate::alerting::{Alert, AlertSeverity};
+use crate::config::{NotifierConfig, NotifiersConfig};
+use crate::notification::{
+    DesktopNotifier, DiscordNotifier, EmailNotifier, NotificationSender, SlackNotifier,
+    TelegramNotifier,
+};
+use std::sync::Arc;
+
+/// A notifier paired with the routing rule that decides whether it receives a
+/// given alert: a minimum severity, and an optional source allow-list.
+pub struct NotifierRoute {
+    pub notifier: Arc<dyn NotificationSender + Send + Sync>,
+    pub min_severity: AlertSeverity,
+    pub sources: Option<Vec<String>>,
+}
+
+impl NotifierRoute {
+    fn matches(&self, alert: &Alert) -> bool {
+        if alert.severity < self.min_severity {
+            return false;
+        }
+        match &self.sources {
+            Some(sources) => sources.iter().any(|s| s == &alert.source),
+            None => true,
+        }
+    }
+}
+
+/// Dispatches alerts to whichever registered notifiers their severity and
+/// source clear, instead of broadcasting every alert to every notifier.
+pub struct NotificationRouter {
+    routes: Vec<NotifierRoute>,
+}
+
+impl NotificationRouter {
+    pub fn new(routes: Vec<NotifierRoute>) -> Self {
+        Self { routes }
+    }
+
+    /// Builds a router from config, constructing each registered notifier backend.
+    pub fn from_config(config: &NotifiersConfig) -> Self {
+        let routes = config
+            .notifiers
+            .iter()
+            .map(|notifier_config| match notifier_config {
+                NotifierConfig::Email {
+                    smtp_server,
+                    smtp_port,
+                    username,
+                    password,
+                    from_address,
+                    to_address,
+                    min_severity,
+                    sources,
+                } => NotifierRoute {
+                    notifier: Arc::new(EmailNotifier::new(
+                        smtp_server.clone(),
+                        *smtp_port,
+                        username.clone(),
+                        password.clone(),
+                        from_address.clone(),
+                        to_address.clone(),
+                    )),
+                    min_severity: min_severity.clone(),
+                    sources: sources.clone(),
+                },
+                NotifierConfig::Slack {
+                    webhook_url,
+                    channel,
+                    min_severity,
+                    sources,
+                } => NotifierRoute {
+                    notifier: Arc::new(SlackNotifier::new(webhook_url.clone(), channel.clone())),
+                    min_severity: min_severity.clone(),
+                    sources: sources.clone(),
+                },
+                NotifierConfig::Discord {
+                    webhook_url,
+                    min_severity,
+                    sources,
+                } => NotifierRoute {
+                    notifier: Arc::new(DiscordNotifier::new(webhook_url.clone())),
+                    min_severity: min_severity.clone(),
+                    sources: sources.clone(),
+                },
+                NotifierConfig::Telegram {
+                    bot_token,
+                    chat_id,
+                    min_severity,
+                    sources,
+                } => NotifierRoute {
+                    notifier: Arc::new(TelegramNotifier::new(bot_token.clone(), chat_id.clone())),
+                    min_severity: min_severity.clone(),
+                    sources: sources.clone(),
+                },
+                NotifierConfig::Desktop {
+                    min_severity,
+                    sources,
+                } => NotifierRoute {
+                    notifier: Arc::new(DesktopNotifier::new()),
+                    min_severity: min_severity.clone(),
+                    sources: sources.clone(),
+                },
+            })
+            .collect();
+
+        Self::new(routes)
+    }
+
+    /// Sends `alert` to every notifier whose route it clears, logging (not
+    /// failing) individual delivery errors so one broken channel doesn't block
+    /// the rest.
+    pub async fn dispatch(&self, alert: &Alert) {
+        for route in &self.routes {
+            if !route.matches(alert) {
+                continue;
+            }
+            if let Err(e) = route.notifier.send(alert).await {
+                log::error!("Failed to send notification: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn alert(severity: AlertSeverity, source: &str) -> Alert {
+        Alert {
+            timestamp: Utc::now(),
+            severity,
+            source: source.to_string(),
+            message: "test".to_string(),
+            details: "test".to_string(),
+        }
+    }
+
+    fn route(min_severity: AlertSeverity, sources: Option<Vec<String>>) -> NotifierRoute {
+        NotifierRoute {
+            notifier: Arc::new(DesktopNotifier::new()),
+            min_severity,
+            sources,
+        }
+    }
+
+    #[test]
+    fn below_min_severity_is_dropped() {
+        let route = route(AlertSeverity::Warning, None);
+        assert!(!route.matches(&alert(AlertSeverity::Info, "Disk")));
+    }
+
+    #[test]
+    fn at_or_above_min_severity_with_no_source_filter_passes() {
+        let route = route(AlertSeverity::Warning, None);
+        assert!(route.matches(&alert(AlertSeverity::Warning, "Disk")));
+        assert!(route.matches(&alert(AlertSeverity::Critical, "Disk")));
+    }
+
+    #[test]
+    fn source_allow_list_includes_listed_sources() {
+        let route = route(AlertSeverity::Info, Some(vec!["Disk".to_string(), "CPU".to_string()]));
+        assert!(route.matches(&alert(AlertSeverity::Info, "CPU")));
+    }
+
+    #[test]
+    fn source_allow_list_excludes_unlisted_sources() {
+        let route = route(AlertSeverity::Info, Some(vec!["Disk".to_string()]));
+        assert!(!route.matches(&alert(AlertSeverity::Info, "Container")));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::alerting::{Alert, AlertError, AlertRule, AlertSeverity};
+use crate::config::TrendRuleConfig;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Simple linear regression slope: covariance(t, v) / variance(t). Returns
+/// `None` when there aren't enough distinct points to fit a line.
+pub fn linear_regression_slope(points: &[(f64, f64)]) -> Option<f64> {
+    if points.len() < 2 {
+        return None;
+    }
+
+    let n = points.len() as f64;
+    let mean_t = points.iter().map(|(t, _)| t).sum::<f64>() / n;
+    let mean_v = points.iter().map(|(_, v)| v).sum::<f64>() / n;
+
+    let covariance: f64 = points.iter().map(|(t, v)| (t - mean_t) * (v - mean_v)).sum();
+    let variance: f64 = points.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+
+    if variance == 0.0 {
+        return None;
+    }
+
+    Some(covariance / variance)
+}
+
+/// An alert rule that projects a metric's growth rate (via linear regression
+/// over a window of `(timestamp, value)` samples) forward to `capacity`, and
+/// fires when the projected time to reach it falls under a threshold.
+pub struct TrendAlertRule {
+    pub config: TrendRuleConfig,
+    pub samples: Vec<(DateTime<Utc>, f64)>,
+}
+
+impl TrendAlertRule {
+    pub fn new(config: TrendRuleConfig, samples: Vec<(DateTime<Utc>, f64)>) -> Self {
+        Self { config, samples }
+    }
+}
+
+#[async_trait]
+impl AlertRule for TrendAlertRule {
+    async fn evaluate(&self) -> Result<Option<Alert>, AlertError> {
+        if self.samples.len() < 2 {
+            return Ok(None);
+        }
+
+        let first_ts = self.samples[0].0;
+        let points: Vec<(f64, f64)> = self
+            .samples
+            .iter()
+            .map(|(ts, value)| ((*ts - first_ts).num_seconds() as f64, *value))
+            .collect();
+
+        let slope_per_second = match linear_regression_slope(&points) {
+            Some(slope) if slope > 0.0 => slope,
+            _ => return Ok(None),
+        };
+
+        let current_value = self.samples.last().map(|(_, v)| *v).unwrap_or(0.0);
+        let remaining = self.config.capacity - current_value;
+
+        if remaining <= 0.0 {
+            return Ok(Some(Alert {
+                timestamp: Utc::now(),
+                severity: AlertSeverity::Critical,
+                source: self.config.source.clone(),
+                message: format!("{} has reached capacity", self.config.metric),
+                details: format!(
+                    "Current: {:.2}, capacity: {:.2}",
+                    current_value, self.config.capacity
+                ),
+            }));
+        }
+
+        let projected_hours = (remaining / slope_per_second) / 3600.0;
+
+        if projected_hours <= self.config.projected_hours_threshold {
+            return Ok(Some(Alert {
+                timestamp: Utc::now(),
+                severity: AlertSeverity::Warning,
+                source: self.config.source.clone(),
+                message: format!(
+                    "{} projected to reach capacity in {:.1}h",
+                    self.config.metric, projected_hours
+                ),
+                details: format!(
+                    "Current: {:.2}, capacity: {:.2}, growth rate: {:.4}/s",
+                    current_value, self.config.capacity, slope_per_second
+                ),
+            }));
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slope_of_a_perfect_line() {
+        let points = [(0.0, 10.0), (1.0, 12.0), (2.0, 14.0), (3.0, 16.0)];
+        let slope = linear_regression_slope(&points).unwrap();
+        assert!((slope - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn slope_of_a_flat_line_is_zero() {
+        let points = [(0.0, 5.0), (1.0, 5.0), (2.0, 5.0)];
+        let slope = linear_regression_slope(&points).unwrap();
+        assert!((slope - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fewer_than_two_points_returns_none() {
+        assert_eq!(linear_regression_slope(&[]), None);
+        assert_eq!(linear_regression_slope(&[(0.0, 1.0)]), None);
+    }
+
+    #[test]
+    fn zero_variance_in_t_returns_none() {
+        // All samples at the same timestamp: variance(t) is 0, which would
+        // otherwise divide by zero.
+        let points = [(1.0, 1.0), (1.0, 2.0), (1.0, 3.0)];
+        assert_eq!(linear_regression_slope(&points), None);
+    }
+}